@@ -14,6 +14,11 @@
 //! vice versa. The internal sync primitives are private and essentially only lock over fast data
 //! moves.
 //!
+//! # Breaking change in 0.2
+//! [`channel`] and [`channel_starting_with`] now require `T: Clone`, to support
+//! [`Updater::subscribe`] feeding an independent [`Receiver`] that always starts caught up to the
+//! current value.
+//!
 //! # Example
 //! ```
 //! use single_value_channel::channel_starting_with;
@@ -31,47 +36,371 @@
 //! assert_eq!(*receiver.latest(), 12);
 //! ```
 
+use std::mem;
 use std::result::Result;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex, Weak};
+use std::task::{Context, Poll, Waker};
+use std::thread::{self, Thread};
+use std::time::{Duration, Instant};
+
+#[cfg(feature = "stream")]
+use std::pin::Pin;
+
+#[cfg(feature = "stream")]
+use futures_core::Stream;
+
+/// A receiver currently waiting for a new value: either a thread blocked in `recv`/
+/// `recv_timeout`/`recv_deadline`, or a task registered via `poll_latest`.
+#[derive(Debug)]
+enum Waiter {
+    Thread(Thread),
+    Task(Waker),
+}
+
+impl Waiter {
+    fn wake(self) {
+        match self {
+            Waiter::Thread(thread) => thread.unpark(),
+            Waiter::Task(waker) => waker.wake(),
+        }
+    }
+}
+
+/// The value a channel is carrying, and how a `Receiver` picks up a fresh copy of it.
+///
+/// `has_changed` is lock-free, but refreshing the value itself still takes `Shared::value`'s
+/// lock and clones -- a true seqlock read (copy `value` via a raw pointer, retrying if `version`
+/// was odd or changed mid-copy) was tried and reverted: it reads `T` concurrently with a writer
+/// still in the middle of storing it, which is UB for any non-`Copy` `T` no matter how the
+/// version check is arranged afterwards. Don't reintroduce it for arbitrary `T`.
+#[derive(Debug)]
+struct ValueState<T> {
+    value: T,
+    /// Captured `T::clone` so a read can copy `value` out without consuming it: the slot must
+    /// stay populated with the true current value, since an `Updater::subscribe` arriving later
+    /// needs to read it too. Stored here rather than re-required on every read method, so
+    /// `Receiver::latest`/`recv`/`poll_latest` don't need their own `T: Clone` bounds -- only
+    /// constructing a channel (which is where this is set) does. Keeping the value behind this
+    /// lock, rather than an `Arc<T>`, also avoids adding an implicit `T: Sync` requirement to
+    /// `Receiver<T>: Send`.
+    clone_fn: fn(&T) -> T,
+}
+
+/// State shared between a `Receiver` and its `Updater`s.
+#[derive(Debug)]
+struct Shared<T> {
+    value: Mutex<ValueState<T>>,
+    version: AtomicU64,
+    /// Assigns each `Receiver` of this channel (the original and every one from `subscribe`) a
+    /// distinct id, so their entries below don't clobber each other.
+    next_receiver_id: AtomicU64,
+    /// One entry per `Receiver` currently blocked in `recv`/`recv_timeout`/`recv_deadline`/
+    /// `poll_latest`, keyed by that receiver's id.
+    waiters: Mutex<Vec<(u64, Waiter)>>,
+    /// Set, before any wake fires, once the last `Updater` is dropped. Checked by
+    /// `Receiver::has_no_updater` instead of `Arc::weak_count`, whose decrement only happens
+    /// *after* `Drop for Updater` returns -- too late to avoid a receiver racing the register
+    /// step in `recv`/`recv_deadline`/`poll_latest` and parking forever.
+    closed: AtomicBool,
+    /// Number of `Updater`s currently alive for this channel. `Arc::weak_count` can't answer
+    /// "am I the last one dropping" reliably -- it's documented as non-synchronizing, and its
+    /// own decrement happens only after `Drop for Updater` returns, so concurrently-dropping
+    /// clones can all observe a count `> 1` and none of them ever close the channel. Tracked
+    /// here instead: `Updater::clone` increments it, and `Drop for Updater`'s `fetch_sub` is the
+    /// actual point of serialization, so exactly one dropping thread sees the transition to 0.
+    live_updaters: AtomicUsize,
+}
+
+impl<T: Clone> Shared<T> {
+    fn new(initial: T) -> Self {
+        Shared {
+            value: Mutex::new(ValueState {
+                value: initial,
+                clone_fn: T::clone,
+            }),
+            version: AtomicU64::new(0),
+            next_receiver_id: AtomicU64::new(0),
+            waiters: Mutex::new(Vec::new()),
+            closed: AtomicBool::new(false),
+            live_updaters: AtomicUsize::new(1),
+        }
+    }
+}
+
+impl<T> Shared<T> {
+    /// Assigns a fresh id to a newly created `Receiver` of this channel.
+    fn next_receiver_id(&self) -> u64 {
+        self.next_receiver_id.fetch_add(1, Ordering::Relaxed)
+    }
+
+    /// Records the value, bumps the version and wakes every currently waiting receiver, if any.
+    fn set_value(&self, value: T) {
+        let mut state = self.value.lock().unwrap();
+        state.value = value;
+        // Bumped while still holding `value`'s lock, so any reader taking the same lock to read
+        // `version` (see `Receiver::try_refresh` below) always sees the two updated together.
+        self.version.fetch_add(1, Ordering::SeqCst);
+        drop(state);
+        self.wake_all();
+    }
+
+    /// Wakes every receiver currently registered as waiting, whether parked in `recv`/
+    /// `recv_timeout`/`recv_deadline` or polled via `poll_latest`.
+    fn wake_all(&self) {
+        for (_, waiter) in mem::take(&mut *self.waiters.lock().unwrap()) {
+            waiter.wake();
+        }
+    }
+
+    /// Registers `id`'s waiter, replacing any previous registration for that same id.
+    fn register_waiter(&self, id: u64, waiter: Waiter) {
+        let mut waiters = self.waiters.lock().unwrap();
+        waiters.retain(|(waiting_id, _)| *waiting_id != id);
+        waiters.push((id, waiter));
+    }
+
+    /// Removes `id`'s waiter, if still registered (e.g. after giving up on a timeout).
+    fn unregister_waiter(&self, id: u64) {
+        self.waiters
+            .lock()
+            .unwrap()
+            .retain(|(waiting_id, _)| *waiting_id != id);
+    }
+}
 
 /// The receiving-half of the single value channel.
 #[derive(Debug)]
 pub struct Receiver<T> {
     latest: T,
-    latest_set: Arc<Mutex<Option<T>>>,
+    /// The shared `version` this receiver's `latest` was cloned from.
+    last_seen: u64,
+    /// This receiver's own id, distinguishing its waiter registration from any other
+    /// `Receiver` of the same channel.
+    id: u64,
+    shared: Arc<Shared<T>>,
 }
 
 impl<T> Receiver<T> {
-    fn update_latest(&mut self) {
-        if let Ok(mut latest_set) = self.latest_set.lock() {
-            if let Some(value) = latest_set.take() {
-                self.latest = value;
-            }
+    /// Returns true if an `Updater` has sent a value that this receiver hasn't picked up yet,
+    /// without taking `value`'s lock at all — just an atomic load compared to the cached
+    /// version last seen.
+    pub fn has_changed(&self) -> bool {
+        self.shared.version.load(Ordering::Acquire) != self.last_seen
+    }
+
+    /// Clones the shared value into `latest` if its version has moved on since we last saw it.
+    /// Returns whether a fresh value was picked up.
+    ///
+    /// The shared slot is always left holding the true current value -- never swapped out -- so
+    /// that a `Receiver` created later by `Updater::subscribe` always sees it, no matter how many
+    /// other receivers already read it.
+    fn try_refresh(&mut self) -> bool {
+        if !self.has_changed() {
+            return false;
         }
+        let state = self.shared.value.lock().unwrap();
+        self.latest = (state.clone_fn)(&state.value);
+        // Read while still holding `value`'s lock, so this always pairs with the exact value
+        // just picked up above (see the matching comment in `Shared::set_value`).
+        self.last_seen = self.shared.version.load(Ordering::SeqCst);
+        drop(state);
+        true
     }
 
     /// Access latest updated value
     pub fn latest(&mut self) -> &T {
-        self.update_latest();
+        self.try_refresh();
         &self.latest
     }
 
     /// Access latest updated value mutably
     pub fn latest_mut(&mut self) -> &mut T {
-        self.update_latest();
+        self.try_refresh();
         &mut self.latest
     }
 
+    /// Registers the current thread to be unparked by the next `Updater::update` (or the drop
+    /// of the last `Updater`), replacing any previous registration for this receiver.
+    fn register_parked(&self) {
+        self.shared
+            .register_waiter(self.id, Waiter::Thread(thread::current()));
+    }
+
+    /// Blocks the current thread until a genuinely new value has been sent by an
+    /// [`Updater`](struct.Updater.html), then returns it.
+    ///
+    /// Returns [`NoUpdaterError`](struct.NoUpdaterError.html) if every `Updater` is dropped
+    /// while waiting, or was already dropped before the call.
+    pub fn recv(&mut self) -> Result<&T, NoUpdaterError> {
+        loop {
+            if self.try_refresh() {
+                return Ok(&self.latest);
+            }
+            if self.has_no_updater() {
+                return Err(NoUpdaterError);
+            }
+
+            self.register_parked();
+
+            // Recheck now that we're registered as parked, to avoid a lost wakeup if an
+            // `Updater` stored a value (or was dropped) between the check above and here.
+            if self.try_refresh() {
+                return Ok(&self.latest);
+            }
+            if self.has_no_updater() {
+                return Err(NoUpdaterError);
+            }
+
+            thread::park();
+        }
+    }
+
+    /// Waits for a genuinely new value, as per [`recv`](struct.Receiver.html#method.recv), but
+    /// only until `timeout` elapses.
+    ///
+    /// Returns [`RecvTimeoutError::Timeout`](enum.RecvTimeoutError.html#variant.Timeout) if
+    /// `timeout` elapses first, or
+    /// [`RecvTimeoutError::NoUpdater`](enum.RecvTimeoutError.html#variant.NoUpdater) if every
+    /// `Updater` is dropped while waiting.
+    pub fn recv_timeout(&mut self, timeout: Duration) -> Result<&T, RecvTimeoutError> {
+        match Instant::now().checked_add(timeout) {
+            Some(deadline) => self.recv_deadline(deadline),
+            // `timeout` is too large to express as a deadline; wait indefinitely instead of
+            // panicking, matching `std::sync::mpsc::Receiver::recv_timeout`.
+            None => self
+                .recv()
+                .map_err(|NoUpdaterError| RecvTimeoutError::NoUpdater),
+        }
+    }
+
+    /// Waits for a genuinely new value, as per [`recv`](struct.Receiver.html#method.recv), but
+    /// only until `deadline` is reached.
+    ///
+    /// Returns [`RecvTimeoutError::Timeout`](enum.RecvTimeoutError.html#variant.Timeout) if
+    /// `deadline` is reached first, or
+    /// [`RecvTimeoutError::NoUpdater`](enum.RecvTimeoutError.html#variant.NoUpdater) if every
+    /// `Updater` is dropped while waiting.
+    pub fn recv_deadline(&mut self, deadline: Instant) -> Result<&T, RecvTimeoutError> {
+        loop {
+            if self.try_refresh() {
+                return Ok(&self.latest);
+            }
+            if self.has_no_updater() {
+                return Err(RecvTimeoutError::NoUpdater);
+            }
+
+            self.register_parked();
+
+            if self.try_refresh() {
+                return Ok(&self.latest);
+            }
+            if self.has_no_updater() {
+                return Err(RecvTimeoutError::NoUpdater);
+            }
+
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                self.shared.unregister_waiter(self.id);
+                return Err(RecvTimeoutError::Timeout);
+            }
+            thread::park_timeout(remaining);
+        }
+    }
+
     /// Returns true if the all related `Updater` instances have been dropped.
     pub fn has_no_updater(&self) -> bool {
-        Arc::weak_count(&self.latest_set) == 0
+        self.shared.closed.load(Ordering::SeqCst)
+    }
+
+    /// Polls for a genuinely new value, for use inside a `std::future::Future::poll`
+    /// implementation.
+    ///
+    /// Returns `Poll::Ready(Some(value))` once an [`Updater`](struct.Updater.html) sends a new
+    /// value, `Poll::Ready(None)` once every `Updater` has been dropped, or registers `cx`'s
+    /// waker and returns `Poll::Pending` otherwise.
+    pub fn poll_latest(&mut self, cx: &mut Context<'_>) -> Poll<Option<&T>> {
+        if self.try_refresh() {
+            return Poll::Ready(Some(&self.latest));
+        }
+        if self.has_no_updater() {
+            return Poll::Ready(None);
+        }
+
+        self.shared
+            .register_waiter(self.id, Waiter::Task(cx.waker().clone()));
+
+        // Recheck now that we're registered, to avoid a lost wakeup if an `Updater` stored a
+        // value (or was dropped) between the check above and here.
+        if self.try_refresh() {
+            return Poll::Ready(Some(&self.latest));
+        }
+        if self.has_no_updater() {
+            return Poll::Ready(None);
+        }
+
+        Poll::Pending
+    }
+
+    /// Converts this receiver into a [`Stream`](futures_core::Stream) that yields each new
+    /// value sent by an [`Updater`](struct.Updater.html), ending once every updater is dropped.
+    ///
+    /// Requires the `stream` feature and a `T: Clone`, since a `Stream`'s items can't borrow
+    /// from the stream itself.
+    #[cfg(feature = "stream")]
+    pub fn into_stream(self) -> ReceiverStream<T> {
+        ReceiverStream(self)
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        // Without this, a `Receiver`/`ReceiverStream` dropped while still registered -- after
+        // `recv`/`recv_deadline` returned from the recheck that follows registration, or while a
+        // `poll_latest` call has returned `Pending` (e.g. a `tokio::select!` cancelling the
+        // future) -- would leave its entry -- including a cloned `Waker` -- in `waiters` until
+        // some other receiver's update happened to call `wake_all`.
+        self.shared.unregister_waiter(self.id);
+    }
+}
+
+/// A [`Stream`](futures_core::Stream) wrapping a [`Receiver`](struct.Receiver.html), yielding
+/// each new value sent by its paired [`Updater`](struct.Updater.html)s.
+///
+/// Created with [`Receiver::into_stream`](struct.Receiver.html#method.into_stream).
+#[cfg(feature = "stream")]
+#[derive(Debug)]
+pub struct ReceiverStream<T>(Receiver<T>);
+
+#[cfg(feature = "stream")]
+impl<T: Clone + Unpin> Stream for ReceiverStream<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        self.get_mut()
+            .0
+            .poll_latest(cx)
+            .map(|latest| latest.cloned())
     }
 }
 
 /// The updating-half of the single value channel.
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub struct Updater<T> {
-    latest: Weak<Mutex<Option<T>>>,
+    shared: Weak<Shared<T>>,
+}
+
+impl<T> Clone for Updater<T> {
+    fn clone(&self) -> Self {
+        // Counts this clone as a live updater up front, matching `Drop`'s `fetch_sub` below, so
+        // the two can never disagree about how many are currently alive.
+        if let Some(shared) = self.shared.upgrade() {
+            shared.live_updaters.fetch_add(1, Ordering::AcqRel);
+        }
+        Updater {
+            shared: self.shared.clone(),
+        }
+    }
 }
 
 /// An error returned from the [`Updater::update`](struct.Updater.html#method.update) function.
@@ -82,6 +411,23 @@ pub struct Updater<T> {
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
 pub struct NoReceiverError<T>(pub T);
 
+/// An error returned from [`Receiver::recv`](struct.Receiver.html#method.recv) and its variants.
+/// Indicates that every [`Updater`](struct.Updater.html) paired with the receiver has been
+/// dropped, so no new value will ever arrive.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct NoUpdaterError;
+
+/// An error returned from [`Receiver::recv_timeout`](struct.Receiver.html#method.recv_timeout)
+/// and [`Receiver::recv_deadline`](struct.Receiver.html#method.recv_deadline).
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum RecvTimeoutError {
+    /// No new value arrived before the timeout/deadline elapsed.
+    Timeout,
+    /// Every [`Updater`](struct.Updater.html) paired with the receiver has been dropped, so no
+    /// new value will ever arrive.
+    NoUpdater,
+}
+
 impl<T> Updater<T> {
     /// Updates the latest value in this channel, to be accessed the next time
     /// [`Receiver::latest`](struct.Receiver.html#method.latest) or
@@ -90,9 +436,9 @@ impl<T> Updater<T> {
     /// This call will fail with [`NoReceiverError`](struct.NoReceiverError.html) if the receiver
     /// has been dropped.
     pub fn update(&self, value: T) -> Result<(), NoReceiverError<T>> {
-        match self.latest.upgrade() {
-            Some(mutex) => {
-                *mutex.lock().unwrap() = Some(value);
+        match self.shared.upgrade() {
+            Some(shared) => {
+                shared.set_value(value);
                 Ok(())
             }
             None => Err(NoReceiverError(value)),
@@ -102,20 +448,75 @@ impl<T> Updater<T> {
     /// Returns true if the receiver has been dropped. Thus indicating any following call to
     /// [`Updater::update`](struct.Updater.html#method.update) would fail.
     pub fn has_no_receiver(&self) -> bool {
-        self.latest.upgrade().is_none()
+        self.shared.upgrade().is_none()
+    }
+
+    /// Creates an additional, independent [`Receiver`](struct.Receiver.html) fed by this
+    /// channel, much like a broadcast/watch channel's `subscribe`. Each subscribed receiver
+    /// tracks its own "have I seen this update" state, so a slow receiver never blocks the
+    /// others; it just skips straight to the newest value next time it's read.
+    ///
+    /// The new receiver starts already caught up to the current value: its `latest` returns that
+    /// value, but no update sent before this call counts as new for `has_changed`/`recv`.
+    ///
+    /// Returns `None` if every existing [`Receiver`](struct.Receiver.html) for this channel has
+    /// already been dropped, since then there is no shared value left to subscribe to. Use
+    /// [`has_no_receiver`](Self::has_no_receiver) to check beforehand.
+    pub fn subscribe(&self) -> Option<Receiver<T>> {
+        let shared = self.shared.upgrade()?;
+        let id = shared.next_receiver_id();
+        let (latest, last_seen) = {
+            let state = shared.value.lock().unwrap();
+            let latest = (state.clone_fn)(&state.value);
+            let last_seen = shared.version.load(Ordering::SeqCst);
+            (latest, last_seen)
+        };
+        Some(Receiver {
+            latest,
+            last_seen,
+            id,
+            shared,
+        })
+    }
+}
+
+impl<T> Drop for Updater<T> {
+    fn drop(&mut self) {
+        // `fetch_sub` is the actual point of serialization between concurrently-dropping clones:
+        // exactly one of them sees it return 1 (the count transitioning to 0), so exactly one
+        // records that no more updates will ever come and wakes any receiver parked in `recv` so
+        // it can observe that. The flag is set *before* `wake_all`, so every receiver that wakes
+        // up (or re-checks after registering as a waiter, racing this very drop) is guaranteed to
+        // see it.
+        if let Some(shared) = self.shared.upgrade() {
+            if shared.live_updaters.fetch_sub(1, Ordering::AcqRel) == 1 {
+                shared.closed.store(true, Ordering::SeqCst);
+                shared.wake_all();
+            }
+        }
     }
 }
 
 /// Constructs a single value channel with an initial value. Thus initial calls to
 /// [`Receiver::latest`](struct.Receiver.html#method.latest) will return that value until
 /// a [`Updater::update`](struct.Updater.html#method.update) call replaces the latest value.
-pub fn channel_starting_with<T>(initial: T) -> (Receiver<T>, Updater<T>) {
+///
+/// # Breaking change in 0.2
+/// Requires `T: Clone`, unlike prior versions. This is needed so the channel's shared slot can
+/// always keep a copy of the current value, rather than giving it away to whichever `Receiver`
+/// reads it first -- otherwise a later [`Updater::subscribe`](struct.Updater.html#method.subscribe)
+/// (which clones that value) would have no value left to seed its new `Receiver` with.
+pub fn channel_starting_with<T: Clone>(initial: T) -> (Receiver<T>, Updater<T>) {
+    let shared = Arc::new(Shared::new(initial.clone()));
+    let id = shared.next_receiver_id();
     let receiver = Receiver {
         latest: initial,
-        latest_set: Arc::new(Mutex::new(None)),
+        last_seen: 0,
+        id,
+        shared,
     };
     let updater = Updater {
-        latest: Arc::downgrade(&receiver.latest_set),
+        shared: Arc::downgrade(&receiver.shared),
     };
     (receiver, updater)
 }
@@ -127,7 +528,11 @@ pub fn channel_starting_with<T>(initial: T) -> (Receiver<T>, Updater<T>) {
 /// [`Updater::update`](struct.Updater.html#method.update) must be wrapped in an option.
 /// To avoid this consider providing an initial value to the channel with
 /// [`channel_starting_with`](fn.channel_starting_with.html)
-pub fn channel<T>() -> (Receiver<Option<T>>, Updater<Option<T>>) {
+///
+/// # Breaking change in 0.2
+/// Requires `T: Clone`, unlike prior versions -- see
+/// [`channel_starting_with`](fn.channel_starting_with.html#breaking-change-in-02).
+pub fn channel<T: Clone>() -> (Receiver<Option<T>>, Updater<Option<T>>) {
     channel_starting_with(None)
 }
 
@@ -135,8 +540,25 @@ pub fn channel<T>() -> (Receiver<Option<T>>, Updater<Option<T>>) {
 mod test {
     use super::*;
     use std::sync::Barrier;
+    use std::task::Wake;
+    use std::time::Duration;
     use std::{mem, thread};
 
+    struct NoopWake;
+
+    impl Wake for NoopWake {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    fn assert_send<T: Send>() {}
+
+    #[test]
+    fn receiver_is_send_for_a_send_but_not_sync_value() {
+        // `Cell<i32>` is `Send` but not `Sync`; `Receiver<T>` must stay `Send` without requiring
+        // `T: Sync`, which a bare `Arc<T>` shared slot would otherwise impose on every caller.
+        assert_send::<Receiver<std::cell::Cell<i32>>>();
+    }
+
     #[test]
     fn send_recv_value() {
         let (mut recv, send) = channel_starting_with(12);
@@ -277,4 +699,311 @@ mod test {
         val.update(Some(123)).unwrap();
         assert_eq!(*val_get.latest(), Some(123));
     }
+
+    #[test]
+    fn recv_blocks_until_new_value() {
+        let (mut recv, send) = channel_starting_with(0);
+        let (barrier, barrier2) = barrier_pair();
+
+        thread::spawn(move || {
+            barrier2.wait(); // <- recv thread parked
+            thread::sleep(Duration::from_millis(50));
+            send.update(42).unwrap();
+        });
+
+        barrier.wait(); // <- about to call recv
+        assert_eq!(*recv.recv().unwrap(), 42);
+    }
+
+    #[test]
+    fn recv_errors_when_last_updater_dropped() {
+        let (mut recv, send) = channel_starting_with(0);
+        let (barrier, barrier2) = barrier_pair();
+
+        thread::spawn(move || {
+            barrier2.wait(); // <- recv thread parked
+            thread::sleep(Duration::from_millis(50));
+            mem::drop(send);
+        });
+
+        barrier.wait(); // <- about to call recv
+        assert_eq!(recv.recv(), Err(NoUpdaterError));
+    }
+
+    #[test]
+    fn recv_errors_when_many_updaters_drop_concurrently() {
+        // Regression test for closing on the last of several concurrently-dropping clones:
+        // `Arc::weak_count` can't tell which dropping thread is last, since its own decrement
+        // only happens after `Drop for Updater` returns, so a count-based check can race and
+        // leave a parked receiver hanging forever. `recv` blocks below until the drop that
+        // actually closes the channel wakes it, so a regression here would hang the test.
+        const CLONES: usize = 16;
+        let (mut recv, send) = channel_starting_with(0);
+        let barrier = Arc::new(Barrier::new(CLONES + 1));
+
+        let updaters: Vec<_> = (0..CLONES).map(|_| send.clone()).collect();
+        mem::drop(send);
+
+        for updater in updaters {
+            let barrier = Arc::clone(&barrier);
+            thread::spawn(move || {
+                barrier.wait(); // <- recv thread parked
+                thread::sleep(Duration::from_millis(50));
+                mem::drop(updater);
+            });
+        }
+
+        barrier.wait(); // <- about to call recv
+        assert_eq!(recv.recv(), Err(NoUpdaterError));
+    }
+
+    #[test]
+    fn recv_errors_immediately_with_no_updater() {
+        let (mut recv, send) = channel_starting_with(0);
+        mem::drop(send);
+        assert_eq!(recv.recv(), Err(NoUpdaterError));
+    }
+
+    #[test]
+    fn recv_timeout_gets_new_value() {
+        let (mut recv, send) = channel_starting_with(0);
+        let (barrier, barrier2) = barrier_pair();
+
+        thread::spawn(move || {
+            barrier2.wait(); // <- recv thread parked
+            thread::sleep(Duration::from_millis(50));
+            send.update(42).unwrap();
+        });
+
+        barrier.wait(); // <- about to call recv_timeout
+        assert_eq!(*recv.recv_timeout(Duration::from_secs(5)).unwrap(), 42);
+    }
+
+    #[test]
+    fn recv_timeout_elapses_with_no_update() {
+        let (mut recv, _send) = channel_starting_with(0);
+        assert_eq!(
+            recv.recv_timeout(Duration::from_millis(20)),
+            Err(RecvTimeoutError::Timeout)
+        );
+    }
+
+    #[test]
+    fn recv_timeout_errors_when_last_updater_dropped() {
+        let (mut recv, send) = channel_starting_with(0);
+        let (barrier, barrier2) = barrier_pair();
+
+        thread::spawn(move || {
+            barrier2.wait(); // <- recv thread parked
+            thread::sleep(Duration::from_millis(50));
+            mem::drop(send);
+        });
+
+        barrier.wait(); // <- about to call recv_timeout
+        assert_eq!(
+            recv.recv_timeout(Duration::from_secs(5)),
+            Err(RecvTimeoutError::NoUpdater)
+        );
+    }
+
+    #[test]
+    fn recv_timeout_does_not_panic_on_overflowing_duration() {
+        let (mut recv, send) = channel_starting_with(0);
+        let (barrier, barrier2) = barrier_pair();
+
+        thread::spawn(move || {
+            barrier2.wait(); // <- recv thread parked
+            thread::sleep(Duration::from_millis(50));
+            send.update(42).unwrap();
+        });
+
+        barrier.wait(); // <- about to call recv_timeout
+        assert_eq!(*recv.recv_timeout(Duration::MAX).unwrap(), 42);
+    }
+
+    #[test]
+    fn poll_latest_pending_then_ready_on_update() {
+        let (mut recv, send) = channel_starting_with(0);
+        let waker = Waker::from(Arc::new(NoopWake));
+        let mut cx = Context::from_waker(&waker);
+
+        assert_eq!(recv.poll_latest(&mut cx), Poll::Pending);
+
+        send.update(5).unwrap();
+        assert_eq!(recv.poll_latest(&mut cx), Poll::Ready(Some(&5)));
+        assert_eq!(recv.poll_latest(&mut cx), Poll::Pending);
+    }
+
+    #[test]
+    fn poll_latest_ready_none_when_no_updater() {
+        let (mut recv, send) = channel_starting_with(0);
+        mem::drop(send);
+
+        let waker = Waker::from(Arc::new(NoopWake));
+        let mut cx = Context::from_waker(&waker);
+        assert_eq!(recv.poll_latest(&mut cx), Poll::Ready(None));
+    }
+
+    #[test]
+    fn poll_latest_ready_none_after_pending_when_last_updater_drops() {
+        let (mut recv, send) = channel_starting_with(0);
+        let waker = Waker::from(Arc::new(NoopWake));
+        let mut cx = Context::from_waker(&waker);
+
+        // Registers a waiter and returns Pending, since nothing has changed yet.
+        assert_eq!(recv.poll_latest(&mut cx), Poll::Pending);
+
+        // Dropping the last updater after that registration must still be observed on the next
+        // poll -- the only thing driving it is the wake `Drop for Updater` does on its way out.
+        mem::drop(send);
+        assert_eq!(recv.poll_latest(&mut cx), Poll::Ready(None));
+    }
+
+    #[test]
+    #[cfg(feature = "stream")]
+    fn receiver_stream_pending_then_ready_then_none_on_last_updater_drop() {
+        let (recv, send) = channel_starting_with(0);
+        let mut stream = recv.into_stream();
+        let waker = Waker::from(Arc::new(NoopWake));
+        let mut cx = Context::from_waker(&waker);
+
+        assert_eq!(Pin::new(&mut stream).poll_next(&mut cx), Poll::Pending);
+
+        send.update(5).unwrap();
+        assert_eq!(
+            Pin::new(&mut stream).poll_next(&mut cx),
+            Poll::Ready(Some(5))
+        );
+        assert_eq!(Pin::new(&mut stream).poll_next(&mut cx), Poll::Pending);
+
+        // Dropping the last updater while a poll is registered must still be observed on the
+        // next poll, ending the stream with `None`.
+        mem::drop(send);
+        assert_eq!(Pin::new(&mut stream).poll_next(&mut cx), Poll::Ready(None));
+    }
+
+    #[test]
+    fn dropping_a_pending_receiver_unregisters_its_waiter() {
+        let (mut recv, send) = channel_starting_with(0);
+        // A second receiver keeps `Shared` alive (and reachable via `send`) after `recv` drops.
+        let _recv2 = send.subscribe().unwrap();
+        let waker = Waker::from(Arc::new(NoopWake));
+        let mut cx = Context::from_waker(&waker);
+
+        // Registers a waiter and returns Pending, since nothing has changed yet.
+        assert_eq!(recv.poll_latest(&mut cx), Poll::Pending);
+        assert_eq!(
+            send.shared.upgrade().unwrap().waiters.lock().unwrap().len(),
+            1
+        );
+
+        // Dropping the receiver while it's still registered must clear its waiter entry rather
+        // than leaking it (and the `Waker` it holds) until some other update happens to fire.
+        mem::drop(recv);
+        assert_eq!(
+            send.shared.upgrade().unwrap().waiters.lock().unwrap().len(),
+            0
+        );
+    }
+
+    #[test]
+    fn subscribe_starts_caught_up_to_current_value() {
+        let (mut recv1, send) = channel_starting_with(0);
+        send.update(1).unwrap();
+        assert_eq!(*recv1.latest(), 1);
+
+        let mut recv2 = send.subscribe().unwrap();
+        assert_eq!(*recv2.latest(), 1);
+    }
+
+    #[test]
+    fn subscribed_receivers_independently_track_updates() {
+        let (mut recv1, send) = channel_starting_with(0);
+        let mut recv2 = send.subscribe().unwrap();
+
+        send.update(1).unwrap();
+        assert_eq!(*recv1.latest(), 1);
+
+        send.update(2).unwrap();
+        send.update(3).unwrap();
+        // recv2 missed the intermediate `2`, catching straight up to `3`.
+        assert_eq!(*recv2.latest(), 3);
+        assert_eq!(*recv1.latest(), 3);
+    }
+
+    #[test]
+    fn dropping_one_subscriber_does_not_affect_another() {
+        let (recv1, send) = channel_starting_with(0);
+        let mut recv2 = send.subscribe().unwrap();
+
+        mem::drop(recv1);
+        assert!(!send.has_no_receiver());
+
+        send.update(1).unwrap();
+        assert_eq!(*recv2.latest(), 1);
+
+        mem::drop(recv2);
+        assert!(send.has_no_receiver());
+    }
+
+    #[test]
+    fn subscribe_returns_none_with_no_receiver() {
+        let (recv, send) = channel_starting_with(0);
+        mem::drop(recv);
+        assert!(send.subscribe().is_none());
+    }
+
+    #[test]
+    fn two_blocked_receivers_both_wake_on_one_update() {
+        let (mut recv1, send) = channel_starting_with(0);
+        let mut recv2 = send.subscribe().unwrap();
+        let (barrier, barrier2) = barrier_pair();
+
+        let recv1_thread = thread::spawn(move || {
+            barrier2.wait(); // <- both receivers parked
+            recv1.recv().unwrap().to_owned()
+        });
+
+        thread::spawn(move || {
+            barrier.wait(); // <- both receivers parked
+            thread::sleep(Duration::from_millis(50));
+            send.update(42).unwrap();
+        });
+
+        assert_eq!(recv2.recv().unwrap(), &42);
+        assert_eq!(recv1_thread.join().unwrap(), 42);
+    }
+
+    #[test]
+    fn has_changed() {
+        let (mut recv, send) = channel_starting_with(0);
+        assert!(!recv.has_changed());
+
+        send.update(1).unwrap();
+        assert!(recv.has_changed());
+
+        assert_eq!(*recv.latest(), 1);
+        assert!(!recv.has_changed());
+    }
+
+    #[test]
+    fn read_during_concurrent_writes_never_goes_backwards() {
+        let (mut recv, send) = channel_starting_with(0u64);
+        let (barrier, barrier2) = barrier_pair();
+
+        thread::spawn(move || {
+            barrier2.wait(); // <- reading concurrently
+            for num in 1..=5000 {
+                send.update(num).unwrap();
+            }
+        });
+
+        barrier.wait(); // <- writing concurrently
+        let mut last = 0;
+        while last < 5000 {
+            let next = *recv.latest();
+            assert!(next >= last, "value must never go backwards");
+            last = next;
+        }
+    }
 }